@@ -0,0 +1,87 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::app::command::create_user::{CreatedUser, UserWriteRepository};
+use crate::app::command::login::{AuthRepository, AuthenticatedUser};
+use crate::app::query::get_user::{GetUser, UserRepository};
+use crate::app::query::healthcheck::HealthRepository;
+use crate::error::AppError;
+
+#[derive(Clone)]
+pub struct PostgresRepository {
+    pool: PgPool,
+}
+
+impl PostgresRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl UserWriteRepository for PostgresRepository {
+    async fn insert_user(
+        &self,
+        id: Uuid,
+        username: String,
+        password_hash: String,
+    ) -> Result<CreatedUser, AppError> {
+        let record = sqlx::query_as::<_, CreatedUser>(
+            r#"
+            INSERT INTO users (id, username, password_hash)
+            VALUES ($1, $2, $3)
+            RETURNING id, username
+            "#,
+        )
+        .bind(id)
+        .bind(username)
+        .bind(password_hash)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+}
+
+impl UserRepository for PostgresRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<GetUser, AppError> {
+        let record = sqlx::query_as::<_, GetUser>(
+            r#"
+            SELECT id, username, created_at, updated_at
+            FROM users
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        record.ok_or(AppError::NotFound)
+    }
+}
+
+impl HealthRepository for PostgresRepository {
+    async fn ping(&self) -> Result<(), AppError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|_| AppError::ServiceUnavailable)
+    }
+}
+
+impl AuthRepository for PostgresRepository {
+    async fn find_by_username(&self, username: &str) -> Result<Option<AuthenticatedUser>, AppError> {
+        let record = sqlx::query_as::<_, AuthenticatedUser>(
+            r#"
+            SELECT id, username, password_hash
+            FROM users
+            WHERE username = $1
+            "#,
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+}
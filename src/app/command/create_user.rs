@@ -0,0 +1,70 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct CreatedUser {
+    pub id: Uuid,
+    pub username: String,
+}
+
+pub trait UserWriteRepository: Clone {
+    fn insert_user(
+        &self,
+        id: Uuid,
+        username: String,
+        password_hash: String,
+    ) -> impl std::future::Future<Output = Result<CreatedUser, AppError>> + Send;
+}
+
+pub struct CreateUserCommand<R: UserWriteRepository> {
+    repository: R,
+}
+
+impl<R: UserWriteRepository> CreateUserCommand<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, username: String, password: String) -> Result<CreatedUser, AppError> {
+        let password_hash = hash_password(&password)?;
+        let id = Uuid::new_v4();
+        self.repository.insert_user(id, username, password_hash).await
+    }
+}
+
+fn hash_password(password: &str) -> Result<String, AppError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|_| AppError::InternalError)?;
+
+    Ok(hash.to_string())
+}
+
+pub fn verify_password(candidate: &str, stored_hash: &str) -> Result<bool, AppError> {
+    let parsed_hash = PasswordHash::new(stored_hash).map_err(|_| AppError::InternalError)?;
+
+    Ok(Argon2::default()
+        .verify_password(candidate.as_bytes(), &parsed_hash)
+        .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_password_and_round_trips_verification() {
+        let password = "correct horse battery staple";
+
+        let hash = hash_password(password).unwrap();
+
+        assert_ne!(hash, password);
+        assert!(verify_password(password, &hash).unwrap());
+        assert!(!verify_password("wrong password", &hash).unwrap());
+    }
+}
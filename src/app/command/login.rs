@@ -0,0 +1,59 @@
+use jsonwebtoken::EncodingKey;
+use uuid::Uuid;
+
+use crate::app::command::create_user::verify_password;
+use crate::auth::jwt::{self, TokenPair};
+use crate::error::AppError;
+
+/// A valid Argon2 PHC hash with no corresponding account, hashed against during
+/// `execute` when the username doesn't exist so lookup misses and wrong passwords
+/// take the same time — otherwise username existence leaks through response latency.
+const DUMMY_PHC_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$l53lYYGJAEC8ysDkkTIo9A$mITNPDc9zyBqgJ3LTK5fOk9B2r2AyxzNI8j2IKl4r/I";
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AuthenticatedUser {
+    pub id: Uuid,
+    pub username: String,
+    pub password_hash: String,
+}
+
+pub trait AuthRepository: Clone {
+    fn find_by_username(
+        &self,
+        username: &str,
+    ) -> impl std::future::Future<Output = Result<Option<AuthenticatedUser>, AppError>> + Send;
+}
+
+pub struct LoginCommand<R: AuthRepository> {
+    repository: R,
+    encoding_key: EncodingKey,
+}
+
+impl<R: AuthRepository> LoginCommand<R> {
+    pub fn new(repository: R, encoding_key: EncodingKey) -> Self {
+        Self {
+            repository,
+            encoding_key,
+        }
+    }
+
+    pub async fn execute(&self, username: String, password: String) -> Result<TokenPair, AppError> {
+        let user = self.repository.find_by_username(&username).await?;
+
+        let hash = user
+            .as_ref()
+            .map(|u| u.password_hash.as_str())
+            .unwrap_or(DUMMY_PHC_HASH);
+        let password_ok = verify_password(&password, hash)?;
+
+        let Some(user) = user else {
+            return Err(AppError::Unauthorized);
+        };
+        if !password_ok {
+            return Err(AppError::Unauthorized);
+        }
+
+        jwt::issue_token_pair(user.id, &self.encoding_key)
+    }
+}
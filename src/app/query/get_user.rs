@@ -0,0 +1,32 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUser {
+    pub id: Uuid,
+    pub username: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+pub trait UserRepository: Clone {
+    fn find_by_id(&self, id: Uuid) -> impl std::future::Future<Output = Result<GetUser, AppError>> + Send;
+}
+
+pub struct GetUserQuery<Q: UserRepository> {
+    repository: Q,
+}
+
+impl<Q: UserRepository> GetUserQuery<Q> {
+    pub fn new(repository: Q) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<GetUser, AppError> {
+        self.repository.find_by_id(id).await
+    }
+}
@@ -0,0 +1,19 @@
+use crate::error::AppError;
+
+pub trait HealthRepository: Clone {
+    fn ping(&self) -> impl std::future::Future<Output = Result<(), AppError>> + Send;
+}
+
+pub struct HealthcheckQuery<Q: HealthRepository> {
+    repository: Q,
+}
+
+impl<Q: HealthRepository> HealthcheckQuery<Q> {
+    pub fn new(repository: Q) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<(), AppError> {
+        self.repository.ping().await
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_user;
+pub mod healthcheck;
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+
+use crate::app::command::create_user::UserWriteRepository;
+use crate::app::command::login::AuthRepository;
+use crate::app::query::get_user::UserRepository;
+use crate::app::query::healthcheck::HealthRepository;
+use crate::auth::jwt::{self, AccessClaims};
+use crate::di::Container;
+use crate::error::AppError;
+
+impl<R, Q> FromRequestParts<Arc<Container<R, Q>>> for AccessClaims
+where
+    R: UserWriteRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<Container<R, Q>>,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(AppError::Unauthorized)?;
+
+        let (scheme, token) = header.split_once(' ').ok_or(AppError::Unauthorized)?;
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return Err(AppError::Unauthorized);
+        }
+
+        jwt::decode_access_token(token, &state.decoding_key)
+    }
+}
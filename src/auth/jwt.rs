@@ -0,0 +1,80 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+const ACCESS_TOKEN_TTL_SECS: u64 = 15 * 60;
+const REFRESH_TOKEN_TTL_SECS: u64 = 60 * 60 * 24 * 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    Access,
+    Refresh,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessClaims {
+    pub sub: String,
+    pub exp: usize,
+    pub token_type: TokenType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshClaims {
+    pub sub: String,
+    pub exp: usize,
+    pub token_type: TokenType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+fn expires_at(ttl_secs: u64) -> usize {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the unix epoch");
+
+    (now.as_secs() + ttl_secs) as usize
+}
+
+pub fn issue_token_pair(user_id: Uuid, encoding_key: &EncodingKey) -> Result<TokenPair, AppError> {
+    let access_claims = AccessClaims {
+        sub: user_id.to_string(),
+        exp: expires_at(ACCESS_TOKEN_TTL_SECS),
+        token_type: TokenType::Access,
+    };
+    let refresh_claims = RefreshClaims {
+        sub: user_id.to_string(),
+        exp: expires_at(REFRESH_TOKEN_TTL_SECS),
+        token_type: TokenType::Refresh,
+    };
+
+    let access_token = encode(&Header::default(), &access_claims, encoding_key)
+        .map_err(|_| AppError::InternalError)?;
+    let refresh_token = encode(&Header::default(), &refresh_claims, encoding_key)
+        .map_err(|_| AppError::InternalError)?;
+
+    Ok(TokenPair {
+        access_token,
+        refresh_token,
+    })
+}
+
+pub fn decode_access_token(token: &str, decoding_key: &DecodingKey) -> Result<AccessClaims, AppError> {
+    let claims = decode::<AccessClaims>(token, decoding_key, &Validation::default())
+        .map(|data| data.claims)
+        .map_err(|_| AppError::Unauthorized)?;
+
+    if claims.token_type != TokenType::Access {
+        return Err(AppError::Unauthorized);
+    }
+
+    Ok(claims)
+}
@@ -0,0 +1,37 @@
+use jsonwebtoken::{DecodingKey, EncodingKey};
+
+use crate::app::command::create_user::{CreateUserCommand, UserWriteRepository};
+use crate::app::command::login::{AuthRepository, LoginCommand};
+use crate::app::query::get_user::{GetUserQuery, UserRepository};
+use crate::app::query::healthcheck::{HealthRepository, HealthcheckQuery};
+
+pub struct Container<R, Q>
+where
+    R: UserWriteRepository,
+    Q: UserRepository + AuthRepository + HealthRepository,
+{
+    pub create_user_command: CreateUserCommand<R>,
+    pub get_user_query: GetUserQuery<Q>,
+    pub login_command: LoginCommand<Q>,
+    pub healthcheck_query: HealthcheckQuery<Q>,
+    pub decoding_key: DecodingKey,
+}
+
+impl<R, Q> Container<R, Q>
+where
+    R: UserWriteRepository,
+    Q: UserRepository + AuthRepository + HealthRepository,
+{
+    pub fn new(write_repository: R, read_repository: Q, jwt_secret: &str) -> Self {
+        let encoding_key = EncodingKey::from_secret(jwt_secret.as_bytes());
+        let decoding_key = DecodingKey::from_secret(jwt_secret.as_bytes());
+
+        Self {
+            create_user_command: CreateUserCommand::new(write_repository),
+            get_user_query: GetUserQuery::new(read_repository.clone()),
+            login_command: LoginCommand::new(read_repository.clone(), encoding_key),
+            healthcheck_query: HealthcheckQuery::new(read_repository),
+            decoding_key,
+        }
+    }
+}
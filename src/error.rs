@@ -0,0 +1,22 @@
+#[derive(Debug)]
+pub enum AppError {
+    NotFound,
+    InternalError,
+    Conflict,
+    Unauthorized,
+    ServiceUnavailable,
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(ref db_err) = err {
+            let is_users_conflict = db_err.is_unique_violation() && db_err.table() == Some("users");
+
+            if is_users_conflict {
+                return AppError::Conflict;
+            }
+        }
+
+        AppError::InternalError
+    }
+}
@@ -0,0 +1,7 @@
+pub mod adapters;
+pub mod app;
+pub mod auth;
+pub mod di;
+pub mod error;
+pub mod ports;
+pub mod telemetry;
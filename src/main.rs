@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use rust_axum_sqrs_template::{adapters::postgres::PostgresRepository, di::Container, ports::httpapi::Server};
+use sqlx::PgPool;
+
+#[tokio::main]
+async fn main() {
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+    let port: u16 = std::env::var("PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(8080);
+
+    let pool = PgPool::connect(&database_url)
+        .await
+        .expect("failed to connect to database");
+
+    let repository = PostgresRepository::new(pool);
+    let container = Arc::new(Container::new(repository.clone(), repository, &jwt_secret));
+
+    Server::new(port, container).run().await;
+}
@@ -8,12 +8,18 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use tokio::net::TcpListener;
+use tower_http::trace::TraceLayer;
+use uuid::Uuid;
 
 use crate::{
     app::{
-        command::create_user::UserWriteRepository,
-        query::get_user::{GetUser, UserRepository},
+        command::{create_user::UserWriteRepository, login::AuthRepository},
+        query::{
+            get_user::{GetUser, UserRepository},
+            healthcheck::HealthRepository,
+        },
     },
+    auth::jwt::{AccessClaims, TokenPair},
     di::Container,
     error::AppError,
 };
@@ -31,6 +37,15 @@ impl IntoResponse for AppError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "internal server error".to_owned(),
             ),
+            AppError::Conflict => (StatusCode::CONFLICT, "user already exists".to_owned()),
+            AppError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "invalid username or password".to_owned(),
+            ),
+            AppError::ServiceUnavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service unavailable".to_owned(),
+            ),
         };
 
         (status, Json(ErrorResponse { message })).into_response()
@@ -40,7 +55,7 @@ impl IntoResponse for AppError {
 pub struct Server<R, Q>
 where
     R: UserWriteRepository,
-    Q: UserRepository,
+    Q: UserRepository + AuthRepository + HealthRepository,
 {
     port: u16,
     container: Arc<Container<R, Q>>,
@@ -48,12 +63,14 @@ where
 impl<R, Q> Server<R, Q>
 where
     R: UserWriteRepository + Send + Sync + 'static,
-    Q: UserRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
 {
     pub fn new(port: u16, container: Arc<Container<R, Q>>) -> Self {
         Self { port, container }
     }
     pub async fn run(self) {
+        crate::telemetry::init();
+
         let app = get_router(self.container);
         let listener = TcpListener::bind(format!("0.0.0.0:{}", self.port))
             .await
@@ -62,15 +79,18 @@ where
     }
 }
 
+#[tracing::instrument(skip(container))]
 async fn get_user<R, Q>(
     State(container): State<Arc<Container<R, Q>>>,
-    Path(id): Path<i64>,
+    _claims: AccessClaims,
+    Path(id): Path<Uuid>,
 ) -> Result<Json<GetUser>, AppError>
 where
     R: UserWriteRepository + Send + Sync + 'static,
-    Q: UserRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
 {
     let user = container.get_user_query.execute(id).await?;
+    tracing::info!(user_id = %id, "fetched user");
     Ok(Json(user))
 }
 
@@ -80,29 +100,70 @@ struct CreateUserRequest {
     password: String,
 }
 
+#[derive(Serialize)]
+struct CreateUserResponse {
+    id: Uuid,
+}
+
+#[tracing::instrument(skip(container, payload))]
 async fn post_user<R, Q>(
     State(container): State<Arc<Container<R, Q>>>,
     Json(payload): Json<CreateUserRequest>,
-) -> Result<StatusCode, AppError>
+) -> Result<(StatusCode, Json<CreateUserResponse>), AppError>
 where
     R: UserWriteRepository + Send + Sync + 'static,
-    Q: UserRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
 {
-    container
+    let username = payload.username.clone();
+    let created_user = container
         .create_user_command
         .execute(payload.username, payload.password)
         .await?;
-    Ok(StatusCode::CREATED)
+    tracing::info!(%username, user_id = %created_user.id, "created user");
+    Ok((StatusCode::CREATED, Json(CreateUserResponse { id: created_user.id })))
+}
+
+#[derive(Deserialize, Serialize)]
+struct LoginRequest {
+    username: String,
+    password: String,
+}
+
+async fn login_user<R, Q>(
+    State(container): State<Arc<Container<R, Q>>>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<Json<TokenPair>, AppError>
+where
+    R: UserWriteRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
+{
+    let tokens = container
+        .login_command
+        .execute(payload.username, payload.password)
+        .await?;
+    Ok(Json(tokens))
+}
+
+async fn healthcheck<R, Q>(State(container): State<Arc<Container<R, Q>>>) -> Result<StatusCode, AppError>
+where
+    R: UserWriteRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
+{
+    container.healthcheck_query.execute().await?;
+    Ok(StatusCode::OK)
 }
 
 fn get_router<R, Q>(container: Arc<Container<R, Q>>) -> Router
 where
     R: UserWriteRepository + Send + Sync + 'static,
-    Q: UserRepository + Send + Sync + 'static,
+    Q: UserRepository + AuthRepository + HealthRepository + Send + Sync + 'static,
 {
     Router::new()
-        .route("/users/{id}", axum::routing::get(get_user))
-        .route("/users", axum::routing::post(post_user))
+        .route("/users/{id}", axum::routing::get(get_user::<R, Q>))
+        .route("/users", axum::routing::post(post_user::<R, Q>))
+        .route("/users/login", axum::routing::post(login_user::<R, Q>))
+        .route("/healthcheck", axum::routing::get(healthcheck::<R, Q>))
+        .layer(TraceLayer::new_for_http())
         .with_state(container)
 }
 
@@ -117,10 +178,22 @@ mod tests {
     use sqlx::PgPool;
     use tower::ServiceExt;
 
+    const TEST_JWT_SECRET: &str = "test-secret";
+
+    fn test_container(pool: PgPool) -> Arc<Container<PostgresRepository, PostgresRepository>> {
+        let repo = PostgresRepository::new(pool);
+        Arc::new(Container::new(repo.clone(), repo, TEST_JWT_SECRET))
+    }
+
+    fn bearer_header(user_id: Uuid) -> String {
+        let encoding_key = jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes());
+        let tokens = crate::auth::jwt::issue_token_pair(user_id, &encoding_key).unwrap();
+        format!("Bearer {}", tokens.access_token)
+    }
+
     #[sqlx::test]
     async fn test_post_user(pool: PgPool) {
-        let repo = PostgresRepository::new(pool.clone());
-        let container = Arc::new(Container::new(repo.clone(), repo));
+        let container = test_container(pool);
         let app = get_router(container.clone());
 
         let response = app
@@ -155,13 +228,12 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
     }
 
     #[sqlx::test]
     async fn test_get_router(pool: PgPool) {
-        let repo = PostgresRepository::new(pool.clone());
-        let container = Arc::new(Container::new(repo.clone(), repo));
+        let container = test_container(pool);
         let app = get_router(container.clone());
 
         let user = container
@@ -174,6 +246,7 @@ mod tests {
             .oneshot(
                 axum::http::Request::builder()
                     .uri(format!("/users/{}", user.id))
+                    .header("Authorization", bearer_header(user.id))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -187,15 +260,16 @@ mod tests {
     #[sqlx::test]
     async fn test_get_user_not_found(pool: PgPool) {
         // Given
-        let repo = PostgresRepository::new(pool.clone());
-        let container = Arc::new(Container::new(repo.clone(), repo));
+        let container = test_container(pool);
         let app = get_router(container);
+        let unknown_id = Uuid::new_v4();
 
         // When
         let response = app
             .oneshot(
                 axum::http::Request::builder()
-                    .uri("/users/99999")
+                    .uri(format!("/users/{}", unknown_id))
+                    .header("Authorization", bearer_header(unknown_id))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -206,11 +280,120 @@ mod tests {
         assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
     }
 
+    #[sqlx::test]
+    async fn test_get_user_malformed_id(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/users/not-a-uuid")
+                    .header("Authorization", bearer_header(Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test]
+    async fn test_get_user_missing_token(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test]
+    async fn test_get_user_expired_token(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let encoding_key = jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes());
+        let expired_claims = crate::auth::jwt::AccessClaims {
+            sub: Uuid::new_v4().to_string(),
+            exp: 0,
+            token_type: crate::auth::jwt::TokenType::Access,
+        };
+        let expired_token =
+            jsonwebtoken::encode(&jsonwebtoken::Header::default(), &expired_claims, &encoding_key)
+                .unwrap();
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("Authorization", format!("Bearer {}", expired_token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test]
+    async fn test_get_user_rejects_refresh_token(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let encoding_key = jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes());
+        let tokens = crate::auth::jwt::issue_token_pair(Uuid::new_v4(), &encoding_key).unwrap();
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("Authorization", format!("Bearer {}", tokens.refresh_token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test]
+    async fn test_get_user_tampered_token(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let mut token = bearer_header(Uuid::new_v4());
+        token.push_str("tampered");
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("Authorization", token)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[sqlx::test]
     async fn not_found(pool: PgPool) {
         // Given
-        let repo = PostgresRepository::new(pool.clone());
-        let container = Arc::new(Container::new(repo.clone(), repo));
+        let container = test_container(pool);
         let app = get_router(container);
 
         // When
@@ -227,4 +410,78 @@ mod tests {
         // Then
         assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
     }
+
+    #[sqlx::test]
+    async fn test_login_success(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container.clone());
+
+        container
+            .create_user_command
+            .execute("loginuser".to_owned(), "correcthorse".to_owned())
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/users/login")
+                    .method("POST")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        r#"{"username":"loginuser","password":"correcthorse"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[sqlx::test]
+    async fn test_login_wrong_password(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container.clone());
+
+        container
+            .create_user_command
+            .execute("loginuser".to_owned(), "correcthorse".to_owned())
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/users/login")
+                    .method("POST")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        r#"{"username":"loginuser","password":"wrongpassword"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test]
+    async fn test_healthcheck(pool: PgPool) {
+        let container = test_container(pool);
+        let app = get_router(container);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/healthcheck")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }